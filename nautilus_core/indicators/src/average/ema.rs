@@ -0,0 +1,71 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use crate::{
+    indicator::MovingAverage,
+    types::{PeriodType, ValueType},
+};
+
+/// An exponentially weighted moving average with smoothing factor `alpha = 2 / (period + 1)`.
+#[derive(Debug)]
+pub struct ExponentialMovingAverage {
+    pub period: PeriodType,
+    pub alpha: ValueType,
+    pub value: ValueType,
+    pub count: usize,
+}
+
+impl ExponentialMovingAverage {
+    pub fn new(period: PeriodType) -> anyhow::Result<Self> {
+        anyhow::ensure!(period > 0, "period must be > 0, was {period}");
+
+        Ok(Self {
+            period,
+            alpha: 2.0 / (period as ValueType + 1.0),
+            value: 0.0,
+            count: 0,
+        })
+    }
+}
+
+impl MovingAverage for ExponentialMovingAverage {
+    fn value(&self) -> ValueType {
+        self.value
+    }
+
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    fn update_raw(&mut self, value: ValueType) {
+        if self.count == 0 {
+            self.value = value;
+        } else {
+            self.value = self.alpha * value + (1.0 - self.alpha) * self.value;
+        }
+
+        self.count += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_rejects_zero_period() {
+        assert!(ExponentialMovingAverage::new(0).is_err());
+    }
+}
@@ -0,0 +1,69 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+pub mod ema;
+pub mod rma;
+pub mod sma;
+
+use std::fmt::Display;
+
+use anyhow::Result;
+use pyo3::prelude::*;
+
+pub use crate::average::{
+    ema::ExponentialMovingAverage, rma::WilderMovingAverage, sma::SimpleMovingAverage,
+};
+use crate::{indicator::MovingAverage, types::PeriodType};
+
+/// The type of moving average to use when constructing an indicator through
+/// [`MovingAverageFactory`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub enum MovingAverageType {
+    /// An unweighted simple moving average.
+    Simple,
+    /// An exponentially weighted moving average.
+    Exponential,
+    /// Wilder's recursive moving average (RMA), as used in his original ATR and RSI.
+    Wilder,
+}
+
+impl Display for MovingAverageType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Simple => "SIMPLE",
+            Self::Exponential => "EXPONENTIAL",
+            Self::Wilder => "WILDER",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Creates a boxed [`MovingAverage`] for the given [`MovingAverageType`] and period.
+pub struct MovingAverageFactory;
+
+impl MovingAverageFactory {
+    pub fn create(
+        ma_type: MovingAverageType,
+        period: PeriodType,
+    ) -> Result<Box<dyn MovingAverage + Send + 'static>> {
+        Ok(match ma_type {
+            MovingAverageType::Simple => Box::new(SimpleMovingAverage::new(period)?),
+            MovingAverageType::Exponential => Box::new(ExponentialMovingAverage::new(period)?),
+            MovingAverageType::Wilder => Box::new(WilderMovingAverage::new(period)?),
+        })
+    }
+}
@@ -0,0 +1,104 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use anyhow::Result;
+
+use crate::{
+    indicator::MovingAverage,
+    types::{period_to_usize, PeriodType, ValueType},
+};
+
+/// Wilder's recursive moving average (RMA), also known as a "smoothed moving average".
+///
+/// The first `period` inputs are averaged with a simple mean to seed the recursion, after
+/// which each new value is blended in as
+/// `RMA_t = (RMA_{t-1} * (period - 1) + x_t) / period`, equivalent to an exponential moving
+/// average with `alpha = 1 / period`.
+#[derive(Debug)]
+pub struct WilderMovingAverage {
+    pub period: PeriodType,
+    pub value: ValueType,
+    pub count: usize,
+    sum: ValueType,
+}
+
+impl WilderMovingAverage {
+    pub fn new(period: PeriodType) -> Result<Self> {
+        anyhow::ensure!(period > 0, "period must be > 0, was {period}");
+
+        Ok(Self {
+            period,
+            value: 0.0,
+            count: 0,
+            sum: 0.0,
+        })
+    }
+}
+
+impl MovingAverage for WilderMovingAverage {
+    fn value(&self) -> ValueType {
+        self.value
+    }
+
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    fn update_raw(&mut self, value: ValueType) {
+        if self.count < period_to_usize(self.period) {
+            self.sum += value;
+            self.count += 1;
+            self.value = self.sum / self.count as ValueType;
+        } else {
+            self.value =
+                (self.value * (self.period as ValueType - 1.0) + value) / self.period as ValueType;
+            self.count += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeds_with_simple_mean_of_first_period_inputs() {
+        let mut rma = WilderMovingAverage::new(3).unwrap();
+
+        rma.update_raw(1.0);
+        rma.update_raw(2.0);
+        rma.update_raw(3.0);
+
+        assert_eq!(rma.value(), 2.0); // (1 + 2 + 3) / 3
+    }
+
+    #[test]
+    fn recurses_after_seed_period() {
+        let mut rma = WilderMovingAverage::new(3).unwrap();
+
+        rma.update_raw(1.0);
+        rma.update_raw(2.0);
+        rma.update_raw(3.0);
+        rma.update_raw(6.0);
+
+        // RMA_t = (RMA_{t-1} * (period - 1) + x_t) / period = (2 * 2 + 6) / 3
+        assert_eq!(rma.value(), 10.0 / 3.0);
+    }
+
+    #[test]
+    fn new_rejects_zero_period() {
+        assert!(WilderMovingAverage::new(0).is_err());
+    }
+}
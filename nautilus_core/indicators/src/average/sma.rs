@@ -0,0 +1,100 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::VecDeque;
+
+use anyhow::Result;
+
+use crate::{
+    indicator::MovingAverage,
+    types::{period_to_usize, PeriodType, ValueType},
+};
+
+/// An unweighted simple moving average over a rolling window of the last `period` inputs.
+#[derive(Debug)]
+pub struct SimpleMovingAverage {
+    pub period: PeriodType,
+    pub value: ValueType,
+    pub count: usize,
+    inputs: VecDeque<ValueType>,
+    sum: ValueType,
+}
+
+impl SimpleMovingAverage {
+    pub fn new(period: PeriodType) -> Result<Self> {
+        anyhow::ensure!(period > 0, "period must be > 0, was {period}");
+
+        Ok(Self {
+            period,
+            value: 0.0,
+            count: 0,
+            inputs: VecDeque::with_capacity(period_to_usize(period)),
+            sum: 0.0,
+        })
+    }
+}
+
+impl MovingAverage for SimpleMovingAverage {
+    fn value(&self) -> ValueType {
+        self.value
+    }
+
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    fn update_raw(&mut self, value: ValueType) {
+        self.inputs.push_back(value);
+        self.sum += value;
+
+        if self.inputs.len() > period_to_usize(self.period) {
+            if let Some(old) = self.inputs.pop_front() {
+                self.sum -= old;
+            }
+        }
+
+        self.count += 1;
+        self.value = self.sum / self.inputs.len() as ValueType;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_rejects_zero_period() {
+        assert!(SimpleMovingAverage::new(0).is_err());
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "period_type_u16")]
+mod tests_period_type_u16 {
+    use super::*;
+
+    #[test]
+    fn count_survives_past_period_type_range_under_narrowed_period() {
+        // `count` is an unbounded running counter, not a `period`-bounded field, so it must
+        // stay `usize` even when `PeriodType` is narrowed to `u16` by this feature.
+        let mut sma = SimpleMovingAverage::new(3).unwrap();
+
+        for _ in 0..=u32::from(u16::MAX) {
+            sma.update_raw(1.0);
+        }
+
+        assert_eq!(sma.count(), usize::from(u16::MAX) + 1);
+    }
+}
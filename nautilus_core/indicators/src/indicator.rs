@@ -0,0 +1,59 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Debug;
+
+use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+
+use crate::types::ValueType;
+
+/// The base trait for all indicators.
+pub trait Indicator: Debug {
+    /// Returns the indicator name.
+    fn name(&self) -> String;
+
+    /// Returns `true` if the indicator has received at least one input.
+    fn has_inputs(&self) -> bool;
+
+    /// Returns `true` once the indicator has received enough inputs to produce a stable value.
+    fn is_initialized(&self) -> bool;
+
+    /// Updates the indicator with the given quote tick.
+    fn handle_quote_tick(&mut self, tick: &QuoteTick);
+
+    /// Updates the indicator with the given trade tick.
+    fn handle_trade_tick(&mut self, tick: &TradeTick);
+
+    /// Updates the indicator with the given bar.
+    fn handle_bar(&mut self, bar: &Bar);
+
+    /// Resets the indicator to its initial unitialized state.
+    fn reset(&mut self);
+}
+
+/// The base trait for all moving average types.
+pub trait MovingAverage: Debug {
+    /// Returns the current value of the moving average.
+    fn value(&self) -> ValueType;
+
+    /// Returns the number of inputs received.
+    ///
+    /// This is an unbounded running counter, not constrained by `period`, so it stays
+    /// `usize` regardless of the crate's `period_type_u16` feature.
+    fn count(&self) -> usize;
+
+    /// Updates the moving average with the given raw value.
+    fn update_raw(&mut self, value: ValueType);
+}
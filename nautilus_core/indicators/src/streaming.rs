@@ -0,0 +1,75 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Generic streaming traits that let indicators be driven and chained without every call site
+//! reaching for a type-specific `update_raw`/`handle_bar` method.
+
+use nautilus_model::data::bar::Bar;
+
+use crate::types::{price_to_value, ValueType};
+
+/// A type that exposes a high price, for generic streaming input.
+pub trait High {
+    fn high(&self) -> ValueType;
+}
+
+/// A type that exposes a low price, for generic streaming input.
+pub trait Low {
+    fn low(&self) -> ValueType;
+}
+
+/// A type that exposes a close price, for generic streaming input.
+pub trait Close {
+    fn close(&self) -> ValueType;
+}
+
+impl High for Bar {
+    fn high(&self) -> ValueType {
+        let high: f64 = (&self.high).into();
+        price_to_value(high)
+    }
+}
+
+impl Low for Bar {
+    fn low(&self) -> ValueType {
+        let low: f64 = (&self.low).into();
+        price_to_value(low)
+    }
+}
+
+impl Close for Bar {
+    fn close(&self) -> ValueType {
+        let close: f64 = (&self.close).into();
+        price_to_value(close)
+    }
+}
+
+/// Updates an indicator in place from a raw streaming input, without returning a value.
+///
+/// This is a generic counterpart to type-specific methods like `update_raw`/`handle_bar`,
+/// letting callers drive an indicator without knowing its concrete input type.
+pub trait Update<Input> {
+    fn update(&mut self, input: Input);
+}
+
+/// Updates an indicator from a raw streaming input and returns its freshly computed output.
+///
+/// Implementing `Next` lets indicators be chained, feeding one indicator's output directly
+/// into another, instead of every call site manually reading a field after updating.
+pub trait Next<Input> {
+    type Output;
+
+    fn next(&mut self, input: Input) -> Self::Output;
+}
@@ -0,0 +1,94 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Compile-time precision and period-width aliases shared by every indicator in this crate.
+//!
+//! By default indicators store `f64` values over `usize` periods. Building with the
+//! `value_type_f32` and/or `period_type_u16` features narrows these aliases, roughly halving
+//! the per-indicator footprint for large ensembles running in memory-constrained backtests.
+
+/// The floating-point type used for indicator values, `f64` unless `value_type_f32` is enabled.
+#[cfg(not(feature = "value_type_f32"))]
+pub type ValueType = f64;
+/// The floating-point type used for indicator values, narrowed to `f32` by the `value_type_f32` feature.
+#[cfg(feature = "value_type_f32")]
+pub type ValueType = f32;
+
+/// The integer type used for indicator periods, `usize` unless `period_type_u16` is enabled.
+#[cfg(not(feature = "period_type_u16"))]
+pub type PeriodType = usize;
+/// The integer type used for indicator periods, narrowed to `u16` by the `period_type_u16` feature.
+#[cfg(feature = "period_type_u16")]
+pub type PeriodType = u16;
+
+// Both conversions below are feature-dependent: a no-op under the default alias, and a real
+// narrowing/widening conversion once the corresponding feature narrows the alias.
+
+/// Converts a [`PeriodType`] into a `usize`, e.g. for sizing a `VecDeque`.
+#[allow(clippy::unnecessary_cast)]
+#[must_use]
+pub const fn period_to_usize(period: PeriodType) -> usize {
+    period as usize
+}
+
+/// Converts a raw `f64` market price into the crate's configured [`ValueType`].
+#[allow(clippy::unnecessary_cast)]
+#[must_use]
+pub fn price_to_value(price: f64) -> ValueType {
+    price as ValueType
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(not(feature = "value_type_f32"))]
+    fn default_value_type_is_f64() {
+        let value: ValueType = 1.5;
+        assert_eq!(std::mem::size_of_val(&value), std::mem::size_of::<f64>());
+    }
+
+    #[test]
+    #[cfg(feature = "value_type_f32")]
+    fn narrowed_value_type_is_f32() {
+        let value: ValueType = 1.5;
+        assert_eq!(std::mem::size_of_val(&value), std::mem::size_of::<f32>());
+    }
+
+    #[test]
+    #[cfg(not(feature = "period_type_u16"))]
+    fn default_period_type_is_usize() {
+        let period: PeriodType = 14;
+        assert_eq!(std::mem::size_of_val(&period), std::mem::size_of::<usize>());
+    }
+
+    #[test]
+    #[cfg(feature = "period_type_u16")]
+    fn narrowed_period_type_is_u16() {
+        let period: PeriodType = 14;
+        assert_eq!(std::mem::size_of_val(&period), std::mem::size_of::<u16>());
+    }
+
+    #[test]
+    fn period_to_usize_round_trips() {
+        assert_eq!(period_to_usize(14), 14_usize);
+    }
+
+    #[test]
+    fn price_to_value_round_trips() {
+        assert_eq!(price_to_value(1.5), 1.5);
+    }
+}
@@ -13,7 +13,10 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::fmt::{Debug, Display};
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Display},
+};
 
 use anyhow::Result;
 use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
@@ -22,6 +25,9 @@ use pyo3::prelude::*;
 use crate::{
     average::{MovingAverageFactory, MovingAverageType},
     indicator::{Indicator, MovingAverage},
+    streaming::{Close, High, Low, Next, Update},
+    types::{period_to_usize, price_to_value, PeriodType, ValueType},
+    volatility::true_range::TrueRange,
 };
 
 /// An indicator which calculates a Average True Range (ATR) across a rolling window.
@@ -29,16 +35,18 @@ use crate::{
 #[derive(Debug)]
 #[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
 pub struct AverageTrueRange {
-    pub period: usize,
+    pub period: PeriodType,
     pub ma_type: MovingAverageType,
     pub use_previous: bool,
-    pub value_floor: f64,
-    pub value: f64,
+    pub value_floor: ValueType,
+    pub value: ValueType,
     pub count: usize,
     pub is_initialized: bool,
     has_inputs: bool,
-    _previous_close: f64,
+    _tr: TrueRange,
     _ma: Box<dyn MovingAverage + Send + 'static>,
+    _history_capacity: usize,
+    _history: VecDeque<ValueType>,
 }
 
 impl Display for AverageTrueRange {
@@ -77,54 +85,89 @@ impl Indicator for AverageTrueRange {
     }
 
     fn handle_bar(&mut self, bar: &Bar) {
-        self.update_raw((&bar.high).into(), (&bar.low).into(), (&bar.close).into());
+        let high: f64 = (&bar.high).into();
+        let low: f64 = (&bar.low).into();
+        let close: f64 = (&bar.close).into();
+        self.update_raw(
+            price_to_value(high),
+            price_to_value(low),
+            price_to_value(close),
+        );
     }
 
     fn reset(&mut self) {
-        self._previous_close = 0.0;
+        self._tr.reset();
+        if let Ok(ma) = MovingAverageFactory::create(self.ma_type, self.period) {
+            self._ma = ma;
+        }
         self.value = 0.0;
         self.count = 0;
         self.has_inputs = false;
         self.is_initialized = false;
+        self._history.clear();
     }
 }
 
 impl AverageTrueRange {
     pub fn new(
-        period: usize,
+        period: PeriodType,
         ma_type: Option<MovingAverageType>,
         use_previous: Option<bool>,
-        value_floor: Option<f64>,
+        value_floor: Option<ValueType>,
+        history_capacity: Option<usize>,
     ) -> Result<Self> {
+        let history_capacity = history_capacity.unwrap_or(0);
+        let use_previous = use_previous.unwrap_or(true);
+
         Ok(Self {
             period,
-            ma_type: ma_type.unwrap_or(MovingAverageType::Simple),
-            use_previous: use_previous.unwrap_or(true),
+            ma_type: ma_type.unwrap_or(MovingAverageType::Wilder),
+            use_previous,
             value_floor: value_floor.unwrap_or(0.0),
             value: 0.0,
             count: 0,
-            _previous_close: 0.0,
-            _ma: MovingAverageFactory::create(MovingAverageType::Simple, period),
+            _tr: TrueRange::new(use_previous),
+            _ma: MovingAverageFactory::create(
+                ma_type.unwrap_or(MovingAverageType::Wilder),
+                period,
+            )?,
             has_inputs: false,
             is_initialized: false,
+            _history_capacity: history_capacity,
+            _history: VecDeque::with_capacity(history_capacity),
         })
     }
 
-    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
-        if self.use_previous {
-            if !self.has_inputs {
-                self._previous_close = close;
-            }
-            self._ma.update_raw(
-                f64::max(self._previous_close, high) - f64::min(low, self._previous_close),
-            );
-            self._previous_close = close;
-        } else {
-            self._ma.update_raw(high - low);
+    /// Returns the ATR value `lookback` bars ago (`0` is the current value), or `None` if
+    /// fewer than `lookback + 1` values have been recorded in the history buffer.
+    ///
+    /// The history buffer only retains values when `history_capacity` was set on construction.
+    #[must_use]
+    pub fn value_at(&self, lookback: usize) -> Option<ValueType> {
+        self._history.get(lookback).copied()
+    }
+
+    /// Returns an iterator over the retained history, most recent value first.
+    pub fn history(&self) -> impl Iterator<Item = &ValueType> {
+        self._history.iter()
+    }
+
+    fn _record_history(&mut self) {
+        if self._history_capacity == 0 {
+            return;
         }
 
+        self._history.push_front(self.value);
+        self._history.truncate(self._history_capacity);
+    }
+
+    pub fn update_raw(&mut self, high: ValueType, low: ValueType, close: ValueType) {
+        self._tr.update_raw(high, low, close);
+        self._ma.update_raw(self._tr.value);
+
         self._floor_value();
         self.increment_count();
+        self._record_history();
     }
 
     fn _floor_value(&mut self) {
@@ -141,9 +184,166 @@ impl AverageTrueRange {
 
         if !self.is_initialized {
             self.has_inputs = true;
-            if self.count >= self.period {
+            if self.count >= period_to_usize(self.period) {
                 self.is_initialized = true;
             }
         }
     }
-}
\ No newline at end of file
+}
+
+impl Update<ValueType> for AverageTrueRange {
+    /// Updates the indicator from a single price, treating it as a zero-range bar
+    /// (`high == low == close == input`) so, with `use_previous` enabled, the true range is
+    /// driven purely by the close-to-close change from the previous input. With
+    /// `use_previous` disabled every such input has a true range of zero.
+    fn update(&mut self, input: ValueType) {
+        self.update_raw(input, input, input);
+    }
+}
+
+impl Next<ValueType> for AverageTrueRange {
+    type Output = ValueType;
+
+    fn next(&mut self, input: ValueType) -> Self::Output {
+        self.update(input);
+        self.value
+    }
+}
+
+impl<T: High + Low + Close> Update<&T> for AverageTrueRange {
+    fn update(&mut self, input: &T) {
+        self.update_raw(input.high(), input.low(), input.close());
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for AverageTrueRange {
+    type Output = ValueType;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.update(input);
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_rejects_zero_period() {
+        assert!(AverageTrueRange::new(0, None, None, None, None).is_err());
+    }
+
+    #[test]
+    fn value_at_zero_matches_value_after_update() {
+        let mut atr = AverageTrueRange::new(3, None, None, None, Some(5)).unwrap();
+
+        atr.update_raw(12.0, 8.0, 10.0);
+        atr.update_raw(11.0, 9.0, 9.5);
+
+        assert_eq!(atr.value_at(0), Some(atr.value));
+    }
+
+    #[test]
+    fn value_at_tracks_prior_values_in_order() {
+        let mut atr = AverageTrueRange::new(3, None, None, None, Some(5)).unwrap();
+
+        atr.update_raw(12.0, 8.0, 10.0);
+        let first_value = atr.value;
+        atr.update_raw(11.0, 9.0, 9.5);
+        let second_value = atr.value;
+
+        assert_eq!(atr.value_at(0), Some(second_value));
+        assert_eq!(atr.value_at(1), Some(first_value));
+        assert_eq!(atr.value_at(2), None);
+    }
+
+    #[test]
+    fn without_history_capacity_value_at_is_always_none() {
+        let mut atr = AverageTrueRange::new(3, None, None, None, None).unwrap();
+
+        atr.update_raw(12.0, 8.0, 10.0);
+
+        assert_eq!(atr.value_at(0), None);
+    }
+
+    #[test]
+    fn history_is_capped_at_configured_capacity() {
+        let mut atr = AverageTrueRange::new(3, None, None, None, Some(2)).unwrap();
+
+        atr.update_raw(12.0, 8.0, 10.0);
+        atr.update_raw(11.0, 9.0, 9.5);
+        atr.update_raw(10.0, 8.5, 9.0);
+
+        assert_eq!(atr.history().count(), 2);
+    }
+
+    #[test]
+    fn use_previous_false_routes_through_true_range_ignoring_prior_close() {
+        let mut atr =
+            AverageTrueRange::new(3, Some(MovingAverageType::Simple), Some(false), None, None)
+                .unwrap();
+
+        atr.update_raw(12.0, 8.0, 11.5);
+        // Were the previous close honored this bar would be max(11.5, 11) - min(9, 11.5) = 2.5;
+        // with use_previous: false it must stay a plain high - low = 2.0 instead.
+        atr.update_raw(11.0, 9.0, 9.5);
+
+        assert_eq!(atr._tr.value, 2.0);
+        assert_eq!(atr.value, (4.0 + 2.0) / 2.0);
+    }
+
+    #[test]
+    fn next_on_f64_matches_update_raw_on_a_zero_range_bar() {
+        let mut via_next = AverageTrueRange::new(3, None, None, None, None).unwrap();
+        let mut via_update_raw = AverageTrueRange::new(3, None, None, None, None).unwrap();
+
+        let output = Next::next(&mut via_next, 10.0);
+        via_update_raw.update_raw(10.0, 10.0, 10.0);
+
+        assert_eq!(output, via_update_raw.value);
+        assert_eq!(via_next.value, via_update_raw.value);
+    }
+
+    #[test]
+    fn next_on_hlc_input_matches_update_raw() {
+        struct Hlc {
+            high: ValueType,
+            low: ValueType,
+            close: ValueType,
+        }
+
+        impl High for Hlc {
+            fn high(&self) -> ValueType {
+                self.high
+            }
+        }
+
+        impl Low for Hlc {
+            fn low(&self) -> ValueType {
+                self.low
+            }
+        }
+
+        impl Close for Hlc {
+            fn close(&self) -> ValueType {
+                self.close
+            }
+        }
+
+        let mut via_next = AverageTrueRange::new(3, None, None, None, None).unwrap();
+        let mut via_update_raw = AverageTrueRange::new(3, None, None, None, None).unwrap();
+
+        let bar = Hlc {
+            high: 12.0,
+            low: 8.0,
+            close: 10.0,
+        };
+
+        let output = Next::next(&mut via_next, &bar);
+        via_update_raw.update_raw(12.0, 8.0, 10.0);
+
+        assert_eq!(output, via_update_raw.value);
+        assert_eq!(via_next.value, via_update_raw.value);
+    }
+}
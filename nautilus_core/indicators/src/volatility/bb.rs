@@ -0,0 +1,256 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Display},
+};
+
+use anyhow::Result;
+use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{MovingAverageFactory, MovingAverageType},
+    indicator::{Indicator, MovingAverage},
+    types::{period_to_usize, price_to_value, PeriodType, ValueType},
+};
+
+/// An indicator which calculates Bollinger Bands across a rolling window of typical prices
+/// (`(high + low + close) / 3`).
+///
+/// The middle band is a configurable moving average of the typical price. The upper and lower
+/// bands sit `k` standard deviations above and below the middle band, where the standard
+/// deviation is tracked with a rolling sum and sum-of-squares so each update is `O(1)`.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct BollingerBands {
+    pub period: PeriodType,
+    pub k: ValueType,
+    pub ma_type: MovingAverageType,
+    pub upper: ValueType,
+    pub middle: ValueType,
+    pub lower: ValueType,
+    pub count: usize,
+    pub is_initialized: bool,
+    has_inputs: bool,
+    _ma: Box<dyn MovingAverage + Send + 'static>,
+    _prices: VecDeque<ValueType>,
+    _sum: ValueType,
+    _sum_sq: ValueType,
+}
+
+impl Display for BollingerBands {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({},{},{})",
+            self.name(),
+            self.period,
+            self.k,
+            self.ma_type,
+        )
+    }
+}
+
+impl Indicator for BollingerBands {
+    fn name(&self) -> String {
+        stringify!(BollingerBands).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, _tick: &QuoteTick) {
+        // Function body intentionally left blank.
+    }
+
+    fn handle_trade_tick(&mut self, _tick: &TradeTick) {
+        // Function body intentionally left blank.
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        let high: f64 = (&bar.high).into();
+        let low: f64 = (&bar.low).into();
+        let close: f64 = (&bar.close).into();
+        self.update_raw(
+            price_to_value(high),
+            price_to_value(low),
+            price_to_value(close),
+        );
+    }
+
+    fn reset(&mut self) {
+        self.upper = 0.0;
+        self.middle = 0.0;
+        self.lower = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.is_initialized = false;
+        self._prices.clear();
+        self._sum = 0.0;
+        self._sum_sq = 0.0;
+        if let Ok(ma) = MovingAverageFactory::create(self.ma_type, self.period) {
+            self._ma = ma;
+        }
+    }
+}
+
+impl BollingerBands {
+    pub fn new(
+        period: PeriodType,
+        k: Option<ValueType>,
+        ma_type: Option<MovingAverageType>,
+    ) -> Result<Self> {
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Simple);
+
+        Ok(Self {
+            period,
+            k: k.unwrap_or(2.0),
+            ma_type,
+            upper: 0.0,
+            middle: 0.0,
+            lower: 0.0,
+            count: 0,
+            has_inputs: false,
+            is_initialized: false,
+            _ma: MovingAverageFactory::create(ma_type, period)?,
+            _prices: VecDeque::with_capacity(period_to_usize(period)),
+            _sum: 0.0,
+            _sum_sq: 0.0,
+        })
+    }
+
+    pub fn update_raw(&mut self, high: ValueType, low: ValueType, close: ValueType) {
+        let typical_price = (high + low + close) / 3.0;
+
+        self._ma.update_raw(typical_price);
+        self.middle = self._ma.value();
+
+        self._prices.push_back(typical_price);
+        self._sum += typical_price;
+        self._sum_sq += typical_price * typical_price;
+
+        if self._prices.len() > period_to_usize(self.period) {
+            if let Some(old) = self._prices.pop_front() {
+                self._sum -= old;
+                self._sum_sq -= old * old;
+            }
+        }
+
+        // Recenter the rolling variance around `self.middle` (rather than the window's own
+        // simple mean) so the bands remain `k` standard deviations either side of whatever
+        // moving average backs the middle band, including `Exponential`/`Wilder` `ma_type`s.
+        let n = self._prices.len() as ValueType;
+        let sum_sq_about_middle =
+            self._sum_sq - 2.0 * self.middle * self._sum + n * self.middle * self.middle;
+        let variance = (sum_sq_about_middle / n).max(0.0);
+        let stddev = variance.sqrt();
+
+        self.upper = self.middle + self.k * stddev;
+        self.lower = self.middle - self.k * stddev;
+
+        self.increment_count();
+    }
+
+    fn increment_count(&mut self) {
+        self.count += 1;
+
+        if !self.is_initialized {
+            self.has_inputs = true;
+            if self.count >= period_to_usize(self.period) {
+                self.is_initialized = true;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_rejects_zero_period() {
+        assert!(BollingerBands::new(0, None, None).is_err());
+    }
+
+    #[test]
+    fn constant_series_degenerates_to_equal_bands() {
+        let mut bb = BollingerBands::new(3, None, None).unwrap();
+
+        for _ in 0..5 {
+            bb.update_raw(10.0, 10.0, 10.0);
+        }
+
+        assert_eq!(bb.upper, bb.middle);
+        assert_eq!(bb.middle, bb.lower);
+        assert_eq!(bb.middle, 10.0);
+    }
+
+    #[test]
+    fn bands_widen_with_k() {
+        let mut narrow = BollingerBands::new(3, Some(1.0), None).unwrap();
+        let mut wide = BollingerBands::new(3, Some(2.0), None).unwrap();
+
+        for bb in [&mut narrow, &mut wide] {
+            bb.update_raw(12.0, 8.0, 10.0);
+            bb.update_raw(14.0, 9.0, 11.0);
+            bb.update_raw(9.0, 6.0, 7.0);
+        }
+
+        assert!(wide.upper - wide.middle > narrow.upper - narrow.middle);
+    }
+
+    #[test]
+    fn variance_recenters_on_middle_for_non_simple_ma_type() {
+        let mut bb =
+            BollingerBands::new(3, Some(2.0), Some(MovingAverageType::Exponential)).unwrap();
+
+        for (high, low, close) in [
+            (10.0, 10.0, 10.0),
+            (11.0, 11.0, 11.0),
+            (12.0, 12.0, 12.0),
+            (20.0, 20.0, 20.0),
+            (21.0, 21.0, 21.0),
+            (22.0, 22.0, 22.0),
+        ] {
+            bb.update_raw(high, low, close);
+        }
+
+        // With an EMA middle, the stddev must be computed around `middle` (1.174), not around
+        // the window's plain mean of 21.0 (0.816) as it would be for `ma_type: Simple`.
+        assert!((bb.middle - 20.156_25).abs() < 1e-4);
+        assert!((bb.upper - 22.504_51).abs() < 1e-4);
+        assert!((bb.lower - 17.807_99).abs() < 1e-4);
+    }
+
+    #[test]
+    fn becomes_initialized_after_period_updates() {
+        let mut bb = BollingerBands::new(3, None, None).unwrap();
+
+        bb.update_raw(12.0, 8.0, 10.0);
+        bb.update_raw(14.0, 9.0, 11.0);
+        assert!(!bb.is_initialized());
+
+        bb.update_raw(9.0, 6.0, 7.0);
+        assert!(bb.is_initialized());
+    }
+}
@@ -0,0 +1,167 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+use pyo3::prelude::*;
+
+use crate::{
+    indicator::Indicator,
+    types::{price_to_value, ValueType},
+};
+
+/// An indicator which calculates the true range of each bar.
+///
+/// True range is `max(high, previous_close) - min(low, previous_close)`, falling back to
+/// `high - low` until a previous close is available. Set `use_previous` to `false` to always
+/// ignore the previous close and compute the plain `high - low` range instead. It is the
+/// building block for [`AverageTrueRange`](super::atr::AverageTrueRange) and other volatility
+/// indicators.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct TrueRange {
+    pub use_previous: bool,
+    pub value: ValueType,
+    pub count: usize,
+    pub is_initialized: bool,
+    has_inputs: bool,
+    _previous_close: Option<ValueType>,
+}
+
+impl Display for TrueRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}()", self.name())
+    }
+}
+
+impl Indicator for TrueRange {
+    fn name(&self) -> String {
+        stringify!(TrueRange).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, _tick: &QuoteTick) {
+        // Function body intentionally left blank.
+    }
+
+    fn handle_trade_tick(&mut self, _tick: &TradeTick) {
+        // Function body intentionally left blank.
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        let high: f64 = (&bar.high).into();
+        let low: f64 = (&bar.low).into();
+        let close: f64 = (&bar.close).into();
+        self.update_raw(
+            price_to_value(high),
+            price_to_value(low),
+            price_to_value(close),
+        );
+    }
+
+    fn reset(&mut self) {
+        self._previous_close = None;
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.is_initialized = false;
+    }
+}
+
+impl TrueRange {
+    /// Creates a new `TrueRange`. When `use_previous` is `false`, the previous close is never
+    /// consulted and every update is a plain `high - low` range.
+    #[must_use]
+    pub fn new(use_previous: bool) -> Self {
+        Self {
+            use_previous,
+            value: 0.0,
+            count: 0,
+            is_initialized: false,
+            has_inputs: false,
+            _previous_close: None,
+        }
+    }
+
+    pub fn update_raw(&mut self, high: ValueType, low: ValueType, close: ValueType) {
+        let previous_close = if self.use_previous {
+            self._previous_close.unwrap_or(close)
+        } else {
+            close
+        };
+
+        self.value = previous_close.max(high) - low.min(previous_close);
+        self._previous_close = Some(close);
+
+        self.count += 1;
+
+        if !self.is_initialized {
+            self.has_inputs = true;
+            self.is_initialized = true;
+        }
+    }
+}
+
+impl Default for TrueRange {
+    fn default() -> Self {
+        Self::new(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_update_falls_back_to_high_minus_low() {
+        let mut tr = TrueRange::new(true);
+
+        tr.update_raw(12.0, 8.0, 10.0);
+
+        assert_eq!(tr.value, 4.0);
+        assert!(tr.is_initialized());
+    }
+
+    #[test]
+    fn uses_previous_close_once_available() {
+        let mut tr = TrueRange::new(true);
+
+        tr.update_raw(12.0, 8.0, 10.0);
+        tr.update_raw(11.0, 9.0, 9.5);
+
+        // max(prev_close, high) - min(low, prev_close) = max(10, 11) - min(9, 10)
+        assert_eq!(tr.value, 2.0);
+    }
+
+    #[test]
+    fn ignores_previous_close_when_use_previous_is_false() {
+        let mut tr = TrueRange::new(false);
+
+        tr.update_raw(12.0, 8.0, 11.5);
+        tr.update_raw(11.0, 9.0, 9.5);
+
+        // Plain high - low; honoring the prior close would give max(11.5, 11) - min(9, 11.5) = 2.5.
+        assert_eq!(tr.value, 2.0);
+    }
+}
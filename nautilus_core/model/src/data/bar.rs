@@ -0,0 +1,38 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+/// A market price.
+///
+/// Wraps the underlying `f64`; indicators convert a `&Price` into a raw `f64` via `Into`
+/// rather than reaching into the field directly, so this type can later grow fixed-point
+/// precision without touching indicator call sites.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Price(pub f64);
+
+impl From<&Price> for f64 {
+    fn from(price: &Price) -> Self {
+        price.0
+    }
+}
+
+/// An OHLCV price bar.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bar {
+    pub open: Price,
+    pub high: Price,
+    pub low: Price,
+    pub close: Price,
+    pub volume: f64,
+}